@@ -20,6 +20,10 @@ pub enum MoveDirection {
     Down,
     Left,
     Right,
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
     Still,
 }
 
@@ -41,6 +45,128 @@ impl From<i32> for MoveDirection {
     }
 }
 
+impl MoveDirection {
+    /// The single-glyph arrow used to render this direction in compact move notation.
+    pub fn glyph(&self) -> char {
+        match self {
+            Self::Up => '↑',
+            Self::Down => '↓',
+            Self::Left => '←',
+            Self::Right => '→',
+            Self::UpLeft => '↖',
+            Self::UpRight => '↗',
+            Self::DownLeft => '↙',
+            Self::DownRight => '↘',
+            Self::Still => '·',
+        }
+    }
+
+    /// Parses a direction from its [`MoveDirection::glyph`] arrow.
+    pub fn from_glyph(glyph: char) -> Option<Self> {
+        match glyph {
+            '↑' => Some(Self::Up),
+            '↓' => Some(Self::Down),
+            '←' => Some(Self::Left),
+            '→' => Some(Self::Right),
+            '↖' => Some(Self::UpLeft),
+            '↗' => Some(Self::UpRight),
+            '↙' => Some(Self::DownLeft),
+            '↘' => Some(Self::DownRight),
+            _ => None,
+        }
+    }
+
+    /// Flattens this direction into a single-step board offset for a given `row_length`.
+    pub fn offset(&self, row_length: i32) -> i32 {
+        match self {
+            Self::Up => -row_length,
+            Self::Down => row_length,
+            Self::Left => -1,
+            Self::Right => 1,
+            Self::UpLeft => -row_length - 1,
+            Self::UpRight => -row_length + 1,
+            Self::DownLeft => row_length - 1,
+            Self::DownRight => row_length + 1,
+            Self::Still => 0,
+        }
+    }
+
+    /// Resolves a flat board offset into a direction given the board's `row_length`.
+    ///
+    /// Unlike [`From<i32>`], this can name the diagonal directions, since telling a diagonal step
+    /// apart from a vertical one requires knowing how many columns a row spans.
+    pub fn from_offset(offset: i32, row_length: i32) -> Self {
+        for dr in -1..=1 {
+            for dc in -1..=1 {
+                if dr * row_length + dc == offset {
+                    return match (dr, dc) {
+                        (0, 1) => Self::Right,
+                        (0, -1) => Self::Left,
+                        (-1, 0) => Self::Up,
+                        (1, 0) => Self::Down,
+                        (-1, -1) => Self::UpLeft,
+                        (-1, 1) => Self::UpRight,
+                        (1, -1) => Self::DownLeft,
+                        (1, 1) => Self::DownRight,
+                        _ => Self::Still,
+                    };
+                }
+            }
+        }
+        Self::Still
+    }
+}
+
+/// Set of jump offset vectors that defines which moves a board allows.
+///
+/// Each vector is a `(row, column)` step; a jump moves a peg two of these steps over an adjacent
+/// peg. Orthogonal rulesets reproduce the classic English variant, while the diagonal and
+/// triangular rulesets add the extra directions those variants need.
+pub struct MoveRuleset {
+    /// Allowed single-step jump vectors as `(row_delta, column_delta)`.
+    pub steps: Vec<(i32, i32)>,
+}
+
+impl MoveRuleset {
+    /// The four orthogonal directions used by rectangular English-style boards.
+    pub fn orthogonal() -> Self {
+        Self {
+            steps: vec![(0, -1), (-1, 0), (0, 1), (1, 0)],
+        }
+    }
+
+    /// The orthogonal directions plus the four diagonals.
+    pub fn diagonal() -> Self {
+        Self {
+            steps: vec![
+                (0, -1),
+                (-1, 0),
+                (0, 1),
+                (1, 0),
+                (-1, -1),
+                (-1, 1),
+                (1, -1),
+                (1, 1),
+            ],
+        }
+    }
+
+    /// The six directions of a triangular board laid out in a skewed rectangular array.
+    pub fn triangular() -> Self {
+        Self {
+            steps: vec![(0, -1), (0, 1), (-1, 0), (1, 0), (-1, -1), (1, 1)],
+        }
+    }
+
+    /// Flattens the step vectors into board offsets for a given `row_length`.
+    pub fn offsets(&self, row_length: usize) -> Vec<i32> {
+        self.steps
+            .iter()
+            .map(|(dr, dc)| dr * row_length as i32 + dc)
+            .collect()
+    }
+}
+
 /// Representation of a move
 #[derive(Debug)]
 pub struct GameMove {
@@ -57,6 +183,9 @@ pub struct GameMove {
 /// - The board must only contain valid characters
 /// - Column count must not be bigger than [`MAX_COLUMN_COUNT`]
 /// - Every column must be of the same size
+///
+/// Interior positions may hold a `.` so that irregular layouts such as triangular boards can be
+/// described inside the same rectangular array as the rectangular variants.
 pub fn validate_board(board: &Vec<char>) -> bool {
     let size = board.len();
     let mut row_count = 0;
@@ -3,8 +3,9 @@
 //! [Golang example](https://go.dev/test/solitaire.go). The code extends the existing sample so that it can work
 //! with other variations of the puzzle, allowing the user to define a custom board.
 
-use board::{validate_board, GameMove};
+use board::{validate_board, GameMove, MoveDirection, MoveRuleset};
 use itertools::iproduct;
+use std::collections::HashSet;
 use std::{error::Error, fs};
 
 mod board;
@@ -44,12 +45,42 @@ pub struct Solver {
     row_length: usize,
     /// Current state of the board. The state of every position in the board is represented by a character.
     board: Vec<char>,
+    /// The board as first loaded, used to restart the exhaustive traversal from the start state
+    /// even after [`Solver::solve`] has left `board` in a solved configuration.
+    initial_board: Vec<char>,
     /// The center of the board. Last peg must be in center position.
     center: i32,
     /// Number of pegs in the board
     pub peg_count: u32,
     /// Solution represenation.
     pub solution: Vec<GameMove>,
+    /// Pagoda functions used to prune branches that can no longer reach the goal.
+    pagodas: Vec<Pagoda>,
+    /// Whether board states are canonicalized under the board's symmetry group before caching.
+    use_symmetry: bool,
+    /// Board positions that may hold a peg, in row-major order. Each maps to one key bit.
+    playable: Vec<usize>,
+    /// Symmetry transforms of the board, as permutations over the `playable` bit indices.
+    symmetries: Vec<Vec<usize>>,
+    /// Canonical keys of board states proven to have no solution, used to skip re-exploration.
+    dead_ends: HashSet<u128>,
+    /// Jump directions the board allows, as flat board offsets.
+    dirs: Vec<i32>,
+}
+
+/// A pagoda (resource) function used to prune hopeless branches of the search.
+///
+/// A pagoda function assigns a weight to every board position such that for every legal jump
+/// (a peg at `a` jumps over a peg at `b` into an empty `c`, all collinear) the inequality
+/// `w(c) ≤ w(a) + w(b)` holds. The weighted sum of occupied positions is therefore non-increasing
+/// under any move, so a state whose weighted peg sum has dropped below the weight required by the
+/// goal can never reach it and may be pruned.
+#[derive(Debug)]
+struct Pagoda {
+    /// Weight of every board position. Non-playable positions always weigh 0.
+    weights: Vec<i32>,
+    /// Minimum weighted peg sum that the goal position still requires.
+    goal: i32,
 }
 
 impl Solver {
@@ -108,12 +139,280 @@ impl Solver {
             Err("Invalid board")?
         }
 
+        let pagodas = Self::build_pagodas(&board, row_length, center);
+        let playable = (0..board.len())
+            .filter(|&pos| board[pos] == '●' || board[pos] == '○')
+            .collect::<Vec<usize>>();
+        let ruleset = MoveRuleset::orthogonal();
+        let symmetries = Self::build_symmetries(row_length, &playable, center, &ruleset.steps);
+
         Ok(Self {
             row_length,
             center,
+            initial_board: board.clone(),
             board,
             solution,
             peg_count,
+            pagodas,
+            use_symmetry: true,
+            playable,
+            symmetries,
+            dead_ends: HashSet::new(),
+            dirs: ruleset.offsets(row_length),
+        })
+    }
+
+    /// Sets the move ruleset, replacing the default orthogonal jumps.
+    ///
+    /// This lets the solver work on diagonal variants or triangular boards by supplying the extra
+    /// jump directions those layouts need. See [`MoveRuleset`] for the built-in rulesets.
+    pub fn with_ruleset(mut self, ruleset: MoveRuleset) -> Self {
+        self.symmetries = Self::build_symmetries(
+            self.row_length,
+            &self.playable,
+            self.center,
+            &ruleset.steps,
+        );
+        self.dirs = ruleset.offsets(self.row_length);
+        self
+    }
+
+    /// Enables or disables symmetry canonicalization of cached states.
+    ///
+    /// Canonicalizing under the board's dihedral group lets a dead-end discovered via one move
+    /// order short-circuit every mirror or rotation of it. Users solving asymmetric custom boards
+    /// should disable it, since those boards have no non-trivial symmetries to exploit.
+    pub fn with_symmetry(mut self, enabled: bool) -> Self {
+        self.use_symmetry = enabled;
+        self
+    }
+
+    /// Builds the symmetry transforms that are genuine symmetries of the whole problem.
+    ///
+    /// The eight dihedral transforms (four rotations × reflection) are tried against the bounding
+    /// box of the playable cells. A transform is kept only when it (a) maps every playable cell
+    /// onto a playable cell, (b) permutes the `steps` of the active [`MoveRuleset`] — so the jump
+    /// geometry is preserved, which fails for e.g. the triangular step set under a 90° rotation —
+    /// and (c) fixes the `center` when a center finish is required. Folding states under a transform
+    /// that violates any of these would equate states that are not actually interchangeable, so the
+    /// dead-end cache and solution dedup would become unsound; the checks keep only the transforms
+    /// the board truly admits.
+    fn build_symmetries(
+        row_length: usize,
+        playable: &[usize],
+        center: i32,
+        steps: &[(i32, i32)],
+    ) -> Vec<Vec<usize>> {
+        if playable.is_empty() || playable.len() > 128 {
+            return Vec::new();
+        }
+
+        let mut bit_of = std::collections::HashMap::new();
+        for (bit, &pos) in playable.iter().enumerate() {
+            bit_of.insert((pos / row_length, pos % row_length), bit);
+        }
+
+        let min_row = playable.iter().map(|&p| p / row_length).min().unwrap();
+        let max_row = playable.iter().map(|&p| p / row_length).max().unwrap();
+        let min_col = playable.iter().map(|&p| p % row_length).min().unwrap();
+        let max_col = playable.iter().map(|&p| p % row_length).max().unwrap();
+        let height = max_row - min_row + 1;
+        let width = max_col - min_col + 1;
+
+        // The eight dihedral transforms. Each pairs its action on a local (row, col) inside the
+        // bounding box with the linear map it applies to a `(row, column)` step vector, so we can
+        // also check the move ruleset is preserved.
+        type PosTransform = fn(usize, usize, usize, usize) -> (usize, usize);
+        type StepTransform = fn(i32, i32) -> (i32, i32);
+        let transforms: [(PosTransform, StepTransform); 8] = [
+            (|r, c, _h, _w| (r, c), |dr, dc| (dr, dc)),
+            (|r, c, h, _w| (c, h - 1 - r), |dr, dc| (dc, -dr)),
+            (|r, c, h, w| (h - 1 - r, w - 1 - c), |dr, dc| (-dr, -dc)),
+            (|r, c, _h, w| (w - 1 - c, r), |dr, dc| (-dc, dr)),
+            (|r, c, _h, w| (r, w - 1 - c), |dr, dc| (dr, -dc)),
+            (|r, c, h, _w| (h - 1 - r, c), |dr, dc| (-dr, dc)),
+            (|r, c, _h, _w| (c, r), |dr, dc| (dc, dr)),
+            (|r, c, h, w| (w - 1 - c, h - 1 - r), |dr, dc| (-dc, -dr)),
+        ];
+
+        let mut symmetries = Vec::new();
+        for (pos_transform, step_transform) in transforms {
+            if !steps
+                .iter()
+                .all(|&(dr, dc)| steps.contains(&step_transform(dr, dc)))
+            {
+                continue;
+            }
+
+            let mut perm = vec![usize::MAX; playable.len()];
+            let mut valid = true;
+            for (bit, &pos) in playable.iter().enumerate() {
+                let (lr, lc) = (pos / row_length - min_row, pos % row_length - min_col);
+                let (tr, tc) = pos_transform(lr, lc, height, width);
+                match bit_of.get(&(min_row + tr, min_col + tc)) {
+                    Some(&target) => perm[bit] = target,
+                    None => {
+                        valid = false;
+                        break;
+                    }
+                }
+            }
+
+            if valid && center >= 0 {
+                let center = center as usize;
+                let (lr, lc) = (center / row_length - min_row, center % row_length - min_col);
+                let (tr, tc) = pos_transform(lr, lc, height, width);
+                if (min_row + tr) * row_length + (min_col + tc) != center {
+                    valid = false;
+                }
+            }
+
+            if valid {
+                symmetries.push(perm);
+            }
+        }
+
+        symmetries
+    }
+
+    /// Encodes the current peg occupancy of the playable cells into a canonical `u128` key.
+    ///
+    /// When symmetry is enabled the lexicographically smallest transform of the occupancy bitmask
+    /// is returned, so symmetric states collapse to a single key.
+    fn occupancy_key(&self) -> u128 {
+        let mut raw: u128 = 0;
+        for (bit, &pos) in self.playable.iter().enumerate() {
+            if self.board[pos] == '●' {
+                raw |= 1 << bit;
+            }
+        }
+
+        if !self.use_symmetry {
+            return raw;
+        }
+
+        let mut best = raw;
+        for perm in &self.symmetries {
+            let mut transformed: u128 = 0;
+            for (bit, &target) in perm.iter().enumerate() {
+                if raw & (1 << bit) != 0 {
+                    transformed |= 1 << target;
+                }
+            }
+            if transformed < best {
+                best = transformed;
+            }
+        }
+        best
+    }
+
+    /// Encodes the current peg occupancy of the playable cells into a raw, un-canonicalized key.
+    fn raw_occupancy(&self) -> u128 {
+        let mut raw: u128 = 0;
+        for (bit, &pos) in self.playable.iter().enumerate() {
+            if self.board[pos] == '●' {
+                raw |= 1 << bit;
+            }
+        }
+        raw
+    }
+
+    /// Canonicalizes a whole trajectory of raw occupancy keys under the board's symmetry group.
+    ///
+    /// Each kept transform is a symmetry of the entire problem, so applying one to every state of a
+    /// solution yields another valid solution. Taking the lexicographically smallest transform of
+    /// the *sequence* therefore certifies global symmetry: two trajectories share a canonical form
+    /// only when a single transform maps one onto the other, unlike comparing per-state canonical
+    /// keys which can collapse genuinely distinct lines of play.
+    fn canonical_signature(&self, trajectory: &[u128]) -> Vec<u128> {
+        let mut best = trajectory.to_vec();
+        if !self.use_symmetry {
+            return best;
+        }
+
+        for perm in &self.symmetries {
+            let transformed: Vec<u128> = trajectory
+                .iter()
+                .map(|&raw| {
+                    let mut t: u128 = 0;
+                    for (bit, &target) in perm.iter().enumerate() {
+                        if raw & (1 << bit) != 0 {
+                            t |= 1 << target;
+                        }
+                    }
+                    t
+                })
+                .collect();
+            if transformed < best {
+                best = transformed;
+            }
+        }
+        best
+    }
+
+    /// Returns true if the dead-end cache is usable for the current board size.
+    fn caches_states(&self) -> bool {
+        !self.playable.is_empty() && self.playable.len() <= 128
+    }
+
+    /// Builds the library of pagoda functions used for pruning.
+    ///
+    /// The standard construction repeats the period-3 pattern `1, 1, 0` along an axis, which
+    /// satisfies the pagoda inequality for both orthogonal jump directions. Applying the three
+    /// phase shifts along the columns and the three along the rows yields six independent bounds.
+    fn build_pagodas(board: &[char], row_length: usize, center: i32) -> Vec<Pagoda> {
+        let playable = |ch: char| ch == '●' || ch == '○';
+        let mut pagodas = Vec::new();
+
+        for along_rows in [false, true] {
+            for phase in 0..3 {
+                let mut weights = vec![0; board.len()];
+                for (pos, &ch) in board.iter().enumerate() {
+                    if !playable(ch) {
+                        continue;
+                    }
+                    let coord = if along_rows {
+                        pos / row_length
+                    } else {
+                        pos % row_length
+                    };
+                    weights[pos] = if (coord + phase) % 3 == 0 { 0 } else { 1 };
+                }
+
+                let goal = if center >= 0 {
+                    weights[center as usize]
+                } else {
+                    board
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, &ch)| playable(ch))
+                        .map(|(pos, _)| weights[pos])
+                        .min()
+                        .unwrap_or(0)
+                };
+
+                pagodas.push(Pagoda { weights, goal });
+            }
+        }
+
+        pagodas
+    }
+
+    /// Returns true if the current board cannot reach the goal under any pagoda bound.
+    ///
+    /// For each pagoda the weighted sum of occupied positions is compared against the weight the
+    /// goal still requires; because the sum is non-increasing, a branch whose sum has already
+    /// fallen below that threshold is dead and can be abandoned.
+    fn pruned(&self) -> bool {
+        self.pagodas.iter().any(|pagoda| {
+            let sum: i32 = self
+                .board
+                .iter()
+                .enumerate()
+                .filter(|(_, &ch)| ch == '●')
+                .map(|(pos, _)| pagoda.weights[pos])
+                .sum();
+            sum < pagoda.goal
         })
     }
 
@@ -135,9 +434,9 @@ impl Solver {
     ///
     /// If the move exists it returns the tuple `(pos, dir, index)`. Otherwise it returns `(-1, 0, 0)`.
     /// The searching starts from `skip_items` index, so that the same moves aren't repeatedly tried.
-    fn find_next_move(board: &[char], dirs: [i32; 4], skip_items: usize) -> (i32, i32, usize) {
+    fn find_next_move(board: &[char], dirs: &[i32], skip_items: usize) -> (i32, i32, usize) {
         for (i, ((pos, _), dir)) in
-            iproduct!(board.iter().enumerate().filter(|p| *p.1 == '●'), dirs)
+            iproduct!(board.iter().enumerate().filter(|p| *p.1 == '●'), dirs.iter().copied())
                 .skip(skip_items)
                 .enumerate()
         {
@@ -160,7 +459,7 @@ impl Solver {
     pub fn solve(&mut self) -> bool {
         let mut last: i32 = -1;
 
-        let dirs = [-1, -(self.row_length as i32), 1, (self.row_length as i32)];
+        let dirs = self.dirs.clone();
         let mut board = self.board.clone();
         let mut moves: Vec<(i32, i32, usize)> = Vec::new();
         let mut skip_items: usize = 0;
@@ -169,9 +468,18 @@ impl Solver {
         while moves.len() < ((self.peg_count - 1) as usize)
             || !(self.center < 0 || last == self.center)
         {
-            let (pos, dir, last_move_index) = Self::find_next_move(&self.board, dirs, skip_items);
+            let (pos, dir, last_move_index) = Self::find_next_move(&self.board, &dirs, skip_items);
             if pos > 0 {
                 Self::make_move(&mut self.board, pos, dir);
+
+                if self.pruned()
+                    || (self.caches_states() && self.dead_ends.contains(&self.occupancy_key()))
+                {
+                    Self::unmove(&mut self.board, pos, dir);
+                    skip_items = last_move_index + 1;
+                    continue;
+                }
+
                 last = pos + 2 * dir;
                 moves.push((pos, dir, last_move_index));
                 skip_items = 0;
@@ -181,6 +489,10 @@ impl Solver {
                     println!("Moves so far {}/{}", max_move_count, self.peg_count - 1);
                 }
             } else if !moves.is_empty() {
+                if self.caches_states() {
+                    let key = self.occupancy_key();
+                    self.dead_ends.insert(key);
+                }
                 let last_move = moves.pop().unwrap();
                 Self::unmove(&mut self.board, last_move.0, last_move.1);
                 skip_items = last_move.2 + 1;
@@ -193,18 +505,228 @@ impl Solver {
             self.solution.push(GameMove {
                 board: board.clone(),
                 start_pos: pos as usize,
-                direction: dir.into(),
+                direction: MoveDirection::from_offset(dir, self.row_length as i32),
             });
             Self::make_move(&mut board, pos, dir);
         }
         self.solution.push(GameMove {
             board: board.clone(),
             start_pos: 0,
-            direction: 0.into(),
+            direction: MoveDirection::Still,
         });
 
         true
     }
+
+    /// Finds every distinct solution of the board.
+    ///
+    /// The search tree is walked exhaustively, reusing the same pagoda and dead-end pruning as
+    /// [`Solver::solve`]. Sequences that are identical under the board's symmetry group are counted
+    /// once, so mirror and rotation duplicates are not returned. Disable the folding with
+    /// [`Solver::with_symmetry`] to enumerate every sequence separately.
+    pub fn solve_all(&mut self) -> Vec<Vec<GameMove>> {
+        let (solutions, _) = self.enumerate(true, false);
+        solutions
+            .iter()
+            .map(|moves| self.moves_to_solution(moves))
+            .collect()
+    }
+
+    /// Counts the distinct solutions of the board without materializing their boards.
+    ///
+    /// This performs the same traversal as [`Solver::solve_all`] but keeps only the running count,
+    /// which is cheaper for users who just want the number of solutions a custom board admits.
+    pub fn count_solutions(&mut self) -> u64 {
+        self.enumerate(false, false).1
+    }
+
+    /// Walks the search tree, collecting and/or counting the distinct solutions found.
+    ///
+    /// When `collect` is set the move list of every distinct solution is returned; `stop_first`
+    /// stops the traversal as soon as the first solution is reached. Solutions are deduplicated by
+    /// [`Solver::canonical_signature`] of the sequence of raw state keys they pass through, which
+    /// collapses play that a single symmetry transform maps onto another.
+    fn enumerate(&mut self, collect: bool, stop_first: bool) -> (Vec<Vec<(i32, i32)>>, u64) {
+        // Restart from the loaded board; `solve` leaves `self.board` solved on success.
+        self.board = self.initial_board.clone();
+
+        let dirs = self.dirs.clone();
+        let mut moves: Vec<(i32, i32)> = Vec::new();
+        let mut keys: Vec<u128> = Vec::new();
+        let mut signatures: HashSet<Vec<u128>> = HashSet::new();
+        let mut solutions: Vec<Vec<(i32, i32)>> = Vec::new();
+        let mut count: u64 = 0;
+
+        self.traverse(
+            &dirs,
+            &mut moves,
+            &mut keys,
+            self.peg_count,
+            -1,
+            &mut signatures,
+            &mut solutions,
+            &mut count,
+            collect,
+            stop_first,
+        );
+
+        (solutions, count)
+    }
+
+    /// Recursive body of [`Solver::enumerate`].
+    ///
+    /// Returns true if at least one solution was found in the subtree, so that callers passing
+    /// `stop_first` can unwind immediately.
+    #[allow(clippy::too_many_arguments)]
+    fn traverse(
+        &mut self,
+        dirs: &[i32],
+        moves: &mut Vec<(i32, i32)>,
+        keys: &mut Vec<u128>,
+        remaining: u32,
+        last: i32,
+        signatures: &mut HashSet<Vec<u128>>,
+        solutions: &mut Vec<Vec<(i32, i32)>>,
+        count: &mut u64,
+        collect: bool,
+        stop_first: bool,
+    ) -> bool {
+        keys.push(self.raw_occupancy());
+
+        let found = if remaining == 1 && (self.center < 0 || last == self.center) {
+            if signatures.insert(self.canonical_signature(keys)) {
+                *count += 1;
+                if collect {
+                    solutions.push(moves.clone());
+                }
+            }
+            true
+        } else if remaining == 1
+            || self.pruned()
+            || (self.caches_states() && self.dead_ends.contains(&self.occupancy_key()))
+        {
+            false
+        } else {
+            let mut any = false;
+            let mut skip_items = 0;
+            loop {
+                let (pos, dir, last_move_index) =
+                    Self::find_next_move(&self.board, dirs, skip_items);
+                if pos <= 0 {
+                    break;
+                }
+
+                Self::make_move(&mut self.board, pos, dir);
+                moves.push((pos, dir));
+                let sub = self.traverse(
+                    dirs,
+                    moves,
+                    keys,
+                    remaining - 1,
+                    pos + 2 * dir,
+                    signatures,
+                    solutions,
+                    count,
+                    collect,
+                    stop_first,
+                );
+                moves.pop();
+                Self::unmove(&mut self.board, pos, dir);
+
+                any = any || sub;
+                if sub && stop_first {
+                    keys.pop();
+                    return true;
+                }
+                skip_items = last_move_index + 1;
+            }
+
+            if !any && self.caches_states() {
+                let key = self.occupancy_key();
+                self.dead_ends.insert(key);
+            }
+            any
+        };
+
+        keys.pop();
+        found
+    }
+
+    /// Replays a compact move transcript against a starting board.
+    ///
+    /// `moves` is a whitespace-separated list of `<col,row><dir>` tokens as produced by
+    /// [`solution::format_moves`]. Each token is applied as a jump and validated against the
+    /// current board; an error is returned on the first token that is malformed or does not name a
+    /// legal move. On success the returned solver's [`Solver::solution`] holds the replayed steps.
+    pub fn replay(init_board: &str, row_length: usize, moves: &str) -> Result<Self, Box<dyn Error>> {
+        let mut solver = Self::init(init_board, row_length)?;
+        let mut board = solver.board.clone();
+        let width = row_length as i32;
+
+        for token in moves.split_whitespace() {
+            let glyph = token.chars().last().ok_or("Empty move token")?;
+            let direction = MoveDirection::from_glyph(glyph).ok_or("Invalid move direction")?;
+            let coords = &token[..token.len() - glyph.len_utf8()];
+            let (column, row) = coords.split_once(',').ok_or("Invalid move coordinates")?;
+            let column: i32 = column.parse()?;
+            let row: i32 = row.parse()?;
+
+            let pos = (row + 2) * width + (column + 2);
+            let dir = direction.offset(width);
+            if pos < 0
+                || pos as usize >= board.len()
+                || (pos + dir) < 0
+                || (pos + dir) as usize >= board.len()
+                || (pos + 2 * dir) < 0
+                || (pos + 2 * dir) as usize >= board.len()
+            {
+                Err("Illegal move")?
+            }
+            if board[pos as usize] != '●'
+                || board[(pos + dir) as usize] != '●'
+                || board[(pos + 2 * dir) as usize] != '○'
+            {
+                Err("Illegal move")?
+            }
+
+            solver.solution.push(GameMove {
+                board: board.clone(),
+                start_pos: pos as usize,
+                direction: MoveDirection::from_offset(dir, width),
+            });
+            Self::make_move(&mut board, pos, dir);
+        }
+
+        solver.solution.push(GameMove {
+            board: board.clone(),
+            start_pos: 0,
+            direction: MoveDirection::Still,
+        });
+
+        Ok(solver)
+    }
+
+    /// Replays a `(pos, dir)` move list from the initial board into a list of [`GameMove`] steps.
+    fn moves_to_solution(&self, moves: &[(i32, i32)]) -> Vec<GameMove> {
+        let mut board = self.board.clone();
+        let mut solution: Vec<GameMove> = Vec::new();
+
+        for &(pos, dir) in moves {
+            solution.push(GameMove {
+                board: board.clone(),
+                start_pos: pos as usize,
+                direction: MoveDirection::from_offset(dir, self.row_length as i32),
+            });
+            Self::make_move(&mut board, pos, dir);
+        }
+        solution.push(GameMove {
+            board: board.clone(),
+            start_pos: 0,
+            direction: MoveDirection::Still,
+        });
+
+        solution
+    }
 }
 
 #[cfg(test)]
@@ -280,4 +802,109 @@ mod tests {
     fn test_english_peg_2_centers() {
         let _solver = Solver::init(BOARD_TWO_CENTERS, N).unwrap();
     }
+
+    // A minimal one-row board with a single legal jump (`●● ○` → `○○ ●`).
+    const SMALL: &str = ".......
+.......
+..●●○..
+.......
+.......";
+    const N_SMALL: usize = 7 + 1;
+
+    // Three cells on the main diagonal; only a diagonal jump can clear it.
+    const DIAG: &str = ".......
+.......
+..●....
+...●...
+....○..
+.......
+.......";
+    const N_DIAG: usize = 7 + 1;
+
+    #[test]
+    fn test_pruning_preserves_valid_solution() {
+        // The pagoda bound must never prune the only valid line of play: the small board still
+        // solves down to a single peg.
+        let mut solver = Solver::init(SMALL, N_SMALL).unwrap();
+        assert!(solver.solve());
+        assert_eq!(solver.solution.len(), 2);
+        let pegs = solver
+            .solution
+            .last()
+            .unwrap()
+            .board
+            .iter()
+            .filter(|&&ch| ch == '●')
+            .count();
+        assert_eq!(pegs, 1);
+    }
+
+    #[test]
+    fn test_diagonal_board_needs_diagonal_ruleset() {
+        // With only orthogonal jumps the diagonal pegs never touch, so there is no move.
+        let mut orthogonal = Solver::init(DIAG, N_DIAG).unwrap();
+        assert!(!orthogonal.solve());
+        assert_eq!(orthogonal.count_solutions(), 0);
+
+        // The diagonal ruleset resolves it in a single jump.
+        let mut diagonal = Solver::init(DIAG, N_DIAG)
+            .unwrap()
+            .with_ruleset(board::MoveRuleset::diagonal());
+        assert!(diagonal.solve());
+        assert_eq!(diagonal.count_solutions(), 1);
+    }
+
+    #[test]
+    fn test_count_solutions_small_board() {
+        let mut solver = Solver::init(SMALL, N_SMALL).unwrap();
+        assert_eq!(solver.count_solutions(), 1);
+    }
+
+    #[test]
+    fn test_count_solutions_after_solve_resets_board() {
+        // `solve` leaves `board` in its solved configuration; `count_solutions` must still
+        // traverse from the initial board rather than the leftover state.
+        let mut solver = Solver::init(SMALL, N_SMALL).unwrap();
+        assert!(solver.solve());
+        assert_eq!(solver.count_solutions(), 1);
+        assert_eq!(solver.solve_all().len(), 1);
+    }
+
+    #[test]
+    fn test_symmetries_respect_ruleset_and_center() {
+        // The square English board admits all eight dihedral transforms under orthogonal jumps.
+        let solver = Solver::init(BOARD, N).unwrap();
+        assert_eq!(solver.symmetries.len(), 8);
+
+        // The triangular step set is not preserved by a 90° rotation (or the two axis
+        // reflections), so those transforms must be dropped rather than folding states that are
+        // not actually equivalent.
+        let triangular = Solver::init(BOARD, N)
+            .unwrap()
+            .with_ruleset(board::MoveRuleset::triangular());
+        assert!(triangular.symmetries.len() < 8);
+        assert!(!triangular.symmetries.is_empty());
+    }
+
+    #[test]
+    fn test_format_moves_replay_round_trip() {
+        let mut solver = Solver::init(BOARD, N).unwrap();
+        assert!(solver.solve());
+
+        let notation = crate::solution::format_moves(&solver.solution);
+        let replayed = Solver::replay(BOARD, N, &notation).unwrap();
+
+        assert_eq!(replayed.solution.len(), solver.solution.len());
+        assert_eq!(
+            replayed.solution.last().unwrap().board,
+            solver.solution.last().unwrap().board
+        );
+    }
+
+    #[test]
+    fn test_replay_rejects_out_of_bounds_token() {
+        // A large row with an upward jump would run `pos` past the board end while
+        // `pos + 2 * dir` lands back in range; this must error, not panic.
+        assert!(Solver::replay(BOARD, N, "4,9↑").is_err());
+    }
 }
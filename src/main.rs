@@ -6,16 +6,18 @@
 use std::{env, path::Path};
 
 use rust_peg_solver::{
-    solution::{create_gif, create_images, print_solution},
+    solution::{create_gif, create_images, format_moves, print_solution},
     Solver,
 };
 
 /// Output type of the solution
 ///
-/// The solution can be printed in the console ([`PrintText`]). Alternatively an image for every step
-/// ([`CreateImages`]) or a single GIF file ([`CreateGif`]) can be created.
+/// The solution can be printed in the console ([`PrintText`]) or as a compact move list
+/// ([`MoveList`]). Alternatively an image for every step ([`CreateImages`]) or a single GIF file
+/// ([`CreateGif`]) can be created.
 pub enum SolutionMode {
     PrintText,
+    MoveList,
     CreateImages,
     CreateGif,
 }
@@ -41,6 +43,7 @@ pub fn main() {
     let mode: SolutionMode = match args.next() {
         Some(arg) => match arg.as_str() {
             "text" => SolutionMode::PrintText,
+            "moves" => SolutionMode::MoveList,
             "images" => SolutionMode::CreateImages,
             "gif" => SolutionMode::CreateGif,
             _ => SolutionMode::PrintText,
@@ -61,6 +64,7 @@ pub fn main() {
     solver.solve();
     match mode {
         SolutionMode::PrintText => print_solution(&solver.solution),
+        SolutionMode::MoveList => println!("{}", format_moves(&solver.solution)),
         SolutionMode::CreateImages => create_images(&solver.solution, &output_folder),
         SolutionMode::CreateGif => create_gif(&solver.solution, &output_folder),
     }
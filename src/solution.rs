@@ -205,6 +205,22 @@ fn create_solution_gif(image_name: &str, solution: &[GameMove]) -> Result<(), Bo
                 target_pos_row = start_pos_row;
                 target_pos_column = start_pos_column + 2;
             }
+            MoveDirection::UpLeft => {
+                target_pos_row = start_pos_row - 2;
+                target_pos_column = start_pos_column - 2;
+            }
+            MoveDirection::UpRight => {
+                target_pos_row = start_pos_row - 2;
+                target_pos_column = start_pos_column + 2;
+            }
+            MoveDirection::DownLeft => {
+                target_pos_row = start_pos_row + 2;
+                target_pos_column = start_pos_column - 2;
+            }
+            MoveDirection::DownRight => {
+                target_pos_row = start_pos_row + 2;
+                target_pos_column = start_pos_column + 2;
+            }
             MoveDirection::Still => {
                 target_pos_row = start_pos_row;
                 target_pos_column = start_pos_column;
@@ -231,6 +247,31 @@ fn create_solution_gif(image_name: &str, solution: &[GameMove]) -> Result<(), Bo
     Ok(())
 }
 
+/// Formats a solution as a compact, diff-friendly move transcript.
+///
+/// Each move becomes a single `<col,row><dir>` token, where `dir` is the arrow glyph of its
+/// [`MoveDirection`] and the column/row are taken on the border-cleared board so they match what
+/// the user sees. The terminal no-op step is omitted.
+pub fn format_moves(solution: &[GameMove]) -> String {
+    let row_length = solution
+        .first()
+        .and_then(|game_move| game_move.board.iter().position(|&ch| ch == '\n'))
+        .map(|n| n + 1)
+        .unwrap_or(0);
+
+    let mut tokens: Vec<String> = Vec::new();
+    for game_move in solution.iter() {
+        if game_move.direction == MoveDirection::Still {
+            continue;
+        }
+        let column = game_move.start_pos % row_length - 2;
+        let row = game_move.start_pos / row_length - 2;
+        tokens.push(format!("{},{}{}", column, row, game_move.direction.glyph()));
+    }
+
+    tokens.join(" ")
+}
+
 /// Prints solution as text in the console
 pub fn print_solution(solution: &[GameMove]) {
     for game_move in solution.iter() {